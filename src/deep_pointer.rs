@@ -1,5 +1,7 @@
 //! Support for storing pointer paths for easy dereferencing inside the autosplitter logic.
 
+use core::fmt;
+
 use arrayvec::ArrayVec;
 use bytemuck::CheckedBitPattern;
 
@@ -14,7 +16,7 @@ use crate::{Address, Address32, Address64, Error, Process};
 pub struct DeepPointer<const CAP: usize> {
     base_address: Address,
     path: ArrayVec<u64, CAP>,
-    deref_type: DerefType,
+    deref_types: ArrayVec<DerefType, CAP>,
 }
 
 impl<const CAP: usize> Default for DeepPointer<CAP> {
@@ -24,7 +26,7 @@ impl<const CAP: usize> Default for DeepPointer<CAP> {
         Self {
             base_address: Address::default(),
             path: ArrayVec::default(),
-            deref_type: DerefType::default(),
+            deref_types: ArrayVec::default(),
         }
     }
 }
@@ -37,7 +39,18 @@ impl<const CAP: usize> DeepPointer<CAP> {
         Self {
             base_address,
             path: path.iter().cloned().collect(),
-            deref_type,
+            deref_types: path.iter().map(|_| deref_type).collect(),
+        }
+    }
+
+    /// Creates a new DeepPointer following each offset at its own pointer size
+    #[inline]
+    pub fn new_with_widths(base_address: Address, path: &[(u64, DerefType)]) -> Self {
+        assert!(CAP != 0 && CAP >= path.len());
+        Self {
+            base_address,
+            path: path.iter().map(|&(offset, _)| offset).collect(),
+            deref_types: path.iter().map(|&(_, deref_type)| deref_type).collect(),
         }
     }
 
@@ -57,7 +70,7 @@ impl<const CAP: usize> DeepPointer<CAP> {
         Self {
             base_address: base_address.into(),
             path: self.path.clone(),
-            deref_type: self.deref_type,
+            deref_types: self.deref_types.clone(),
         }
     }
 
@@ -78,8 +91,8 @@ impl<const CAP: usize> DeepPointer<CAP> {
             return Err(Error {});
         }
         let (&last, path) = self.path.split_last().ok_or(Error {})?;
-        for &offset in path {
-            address = match self.deref_type {
+        for (&offset, &deref_type) in path.iter().zip(&self.deref_types) {
+            address = match deref_type {
                 DerefType::Bit32 => process.read::<Address32>(address + offset)?.into(),
                 DerefType::Bit64 => process.read::<Address64>(address + offset)?.into(),
             };
@@ -87,6 +100,54 @@ impl<const CAP: usize> DeepPointer<CAP> {
         Ok(address + last)
     }
 
+    /// Dereferences the pointer path, returning the intermediate addresses or a [`DerefFailure`]
+    pub fn deref_offsets_verbose(
+        &self,
+        process: &Process,
+    ) -> Result<(Address, ArrayVec<Address, CAP>), DerefFailure> {
+        self.deref_offsets_verbose_from(process, self.base_address)
+    }
+
+    /// Dereferences the pointer path, starting from the provided `base_address`,
+    /// returning the intermediate addresses or a [`DerefFailure`] locating the failing hop
+    pub fn deref_offsets_verbose_from(
+        &self,
+        process: &Process,
+        base_address: impl Into<Address>,
+    ) -> Result<(Address, ArrayVec<Address, CAP>), DerefFailure> {
+        let mut address = base_address.into();
+        let mut intermediate = ArrayVec::new();
+        if address.is_null() {
+            return Err(DerefFailure {
+                step: 0,
+                address,
+                offset: 0,
+            });
+        }
+        let (&last, path) = self.path.split_last().ok_or(DerefFailure {
+            step: 0,
+            address,
+            offset: 0,
+        })?;
+        for (step, (&offset, &deref_type)) in path.iter().zip(&self.deref_types).enumerate() {
+            address = match deref_type {
+                DerefType::Bit32 => process
+                    .read::<Address32>(address + offset)
+                    .map(Into::into),
+                DerefType::Bit64 => process
+                    .read::<Address64>(address + offset)
+                    .map(Into::into),
+            }
+            .map_err(|_| DerefFailure {
+                step,
+                address,
+                offset,
+            })?;
+            intermediate.push(address);
+        }
+        Ok((address + last, intermediate))
+    }
+
     /// Dereferences the pointer path, returning the value stored at the final memory address
     pub fn deref<T: CheckedBitPattern>(&self, process: &Process) -> Result<T, Error> {
         process.read(self.deref_offsets(process)?)
@@ -101,6 +162,79 @@ impl<const CAP: usize> DeepPointer<CAP> {
     ) -> Result<T, Error> {
         process.read(self.deref_offsets_from(process, base_address)?)
     }
+
+    /// Dereferences the pointer path and extracts `bit_width` bits at `bit_offset` as a `u64`
+    pub fn deref_bits(
+        &self,
+        process: &Process,
+        bit_offset: u64,
+        bit_width: u64,
+    ) -> Result<u64, Error> {
+        self.deref_bits_from(process, self.base_address, bit_offset, bit_width)
+    }
+
+    /// Dereferences the pointer path, starting from the provided `base_address`,
+    /// and extracts `bit_width` bits at `bit_offset` as a `u64`. See [`deref_bits`](Self::deref_bits)
+    pub fn deref_bits_from(
+        &self,
+        process: &Process,
+        base_address: impl Into<Address>,
+        bit_offset: u64,
+        bit_width: u64,
+    ) -> Result<u64, Error> {
+        if bit_width == 0 || bit_width > 64 {
+            return Err(Error {});
+        }
+        let address = self.deref_offsets_from(process, base_address)?;
+        let shift = bit_offset % 8;
+        let read_address = address + bit_offset / 8;
+        let raw: u64 = match covering_bytes(shift + bit_width) {
+            Some(1) => process.read::<u8>(read_address)?.into(),
+            Some(2) => process.read::<u16>(read_address)?.into(),
+            Some(4) => process.read::<u32>(read_address)?.into(),
+            Some(8) => process.read::<u64>(read_address)?,
+            _ => return Err(Error {}),
+        };
+        Ok(extract_bits(raw, shift, bit_width))
+    }
+
+    /// Dereferences the pointer path and reads the single bit at `bit_offset`
+    /// from the final memory address, returning it as a `bool`.
+    pub fn deref_bit(&self, process: &Process, bit_offset: u64) -> Result<bool, Error> {
+        self.deref_bit_from(process, self.base_address, bit_offset)
+    }
+
+    /// Dereferences the pointer path, starting from the provided `base_address`,
+    /// and reads the single bit at `bit_offset` from the final memory address,
+    /// returning it as a `bool`.
+    pub fn deref_bit_from(
+        &self,
+        process: &Process,
+        base_address: impl Into<Address>,
+        bit_offset: u64,
+    ) -> Result<bool, Error> {
+        Ok(self.deref_bits_from(process, base_address, bit_offset, 1)? != 0)
+    }
+}
+
+/// Describes where and how [`deref_offsets_verbose`](DeepPointer::deref_offsets_verbose)
+/// failed to resolve a pointer path.
+#[derive(Copy, Clone, Debug)]
+pub struct DerefFailure {
+    /// The zero-based index of the hop at which dereferencing failed.
+    pub step: usize,
+    /// The last address that was successfully computed before the failure,
+    /// i.e. the base of the unreadable read.
+    pub address: Address,
+    /// The offset that was being applied to `address` when the read faulted.
+    pub offset: u64,
+}
+
+impl From<DerefFailure> for Error {
+    #[inline]
+    fn from(_: DerefFailure) -> Self {
+        Error {}
+    }
 }
 
 /// Describes the pointer size that should be used while deferecencing a pointer path
@@ -112,3 +246,386 @@ pub enum DerefType {
     #[default]
     Bit64,
 }
+
+/// A pointer path parsed from a textual, Cheat-Engine-style / arrow notation such as
+/// `[64] "game.dll"+0x1234 -> 0x10 -> 0x48` (offsets may also be comma-separated).
+///
+/// The base is either a module token or an absolute address; the optional leading
+/// `[32]`/`[64]` width marker defaults to [`DerefType::Bit64`]. Resolve the module to an
+/// [`Address`] and call [`to_deep_pointer`](Self::to_deep_pointer) to build a [`DeepPointer`].
+#[derive(Clone, Debug)]
+pub struct ParsedPath<'a, const CAP: usize> {
+    /// The base module token, if the path is relative to a module.
+    pub module: Option<&'a str>,
+    /// The absolute base address, if the path does not start from a module.
+    pub base_address: Option<u64>,
+    /// The offsets following the base, in order.
+    pub offsets: ArrayVec<u64, CAP>,
+    /// The pointer width to follow each offset at.
+    pub deref_type: DerefType,
+}
+
+impl<'a, const CAP: usize> ParsedPath<'a, CAP> {
+    /// Parses a pointer path from its textual notation, see [`ParsedPath`] for the syntax
+    pub fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let mut deref_type = DerefType::default();
+
+        // Optional leading `[32]`/`[64]` width marker.
+        let rest = input.trim_start();
+        let mut cursor = input.len() - rest.len();
+        let rest = if let Some(after) = rest.strip_prefix('[') {
+            let end = after
+                .find(']')
+                .ok_or(ParseError::new(cursor, ParseErrorKind::UnterminatedWidth))?;
+            deref_type = match after[..end].trim() {
+                "32" => DerefType::Bit32,
+                "64" => DerefType::Bit64,
+                _ => return Err(ParseError::new(cursor + 1, ParseErrorKind::InvalidWidth)),
+            };
+            cursor += 1 + end + 1;
+            input[cursor..].trim_start()
+        } else {
+            rest
+        };
+        cursor = input.len() - rest.len();
+
+        let mut module = None;
+        let mut base_address = None;
+        let mut offsets = ArrayVec::<u64, CAP>::new();
+
+        let mut first = true;
+        for segment in SplitSegments::new(rest, cursor) {
+            let (text, at) = segment;
+            let trimmed = text.trim();
+            let offset_at = at + leading_ws(text);
+            if first && trimmed.starts_with('"') {
+                // Quoted module, optionally followed by `+offset`.
+                let after = &trimmed[1..];
+                let end = after
+                    .find('"')
+                    .ok_or(ParseError::new(offset_at, ParseErrorKind::UnterminatedModule))?;
+                module = Some(&after[..end]);
+                let tail = after[end + 1..].trim_start();
+                if let Some(num) = tail.strip_prefix('+') {
+                    offsets
+                        .try_push(parse_int(num, offset_at)?)
+                        .map_err(|_| ParseError::new(offset_at, ParseErrorKind::TooManyOffsets))?;
+                } else if !tail.is_empty() {
+                    return Err(ParseError::new(offset_at, ParseErrorKind::InvalidOffset));
+                }
+            } else if first && matches!(trimmed.find('+'), Some(plus) if plus != 0) {
+                // Bare module token, e.g. `game.dll+0x1234`. A `+` at index 0 is a
+                // signed absolute base, not an empty module, and falls through below.
+                let plus = trimmed.find('+').unwrap();
+                module = Some(trimmed[..plus].trim());
+                offsets
+                    .try_push(parse_int(&trimmed[plus + 1..], offset_at)?)
+                    .map_err(|_| ParseError::new(offset_at, ParseErrorKind::TooManyOffsets))?;
+            } else if first {
+                // Absolute base address.
+                base_address = Some(parse_int(trimmed, offset_at)?);
+            } else {
+                offsets
+                    .try_push(parse_int(trimmed, offset_at)?)
+                    .map_err(|_| ParseError::new(offset_at, ParseErrorKind::TooManyOffsets))?;
+            }
+            first = false;
+        }
+
+        if first {
+            return Err(ParseError::new(cursor, ParseErrorKind::Empty));
+        }
+
+        Ok(Self {
+            module,
+            base_address,
+            offsets,
+            deref_type,
+        })
+    }
+
+    /// Builds a [`DeepPointer`], using the resolved module `base_address` unless the path
+    /// parsed an absolute base address, in which case `base_address` is ignored
+    pub fn to_deep_pointer(&self, base_address: impl Into<Address>) -> DeepPointer<CAP> {
+        let base = match self.base_address {
+            Some(address) => Address::new(address),
+            None => base_address.into(),
+        };
+        DeepPointer::new(base, self.deref_type, &self.offsets)
+    }
+}
+
+impl<const CAP: usize> fmt::Display for ParsedPath<'_, CAP> {
+    /// Serializes the path back into the notation accepted by [`parse`](Self::parse)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = match self.deref_type {
+            DerefType::Bit32 => "32",
+            DerefType::Bit64 => "64",
+        };
+        write!(f, "[{width}] ")?;
+        let mut offsets = self.offsets.iter();
+        if let Some(module) = self.module {
+            write!(f, "\"{module}\"")?;
+            if let Some(first) = offsets.next() {
+                write!(f, "+{first:#X}")?;
+            }
+        } else {
+            write!(f, "{:#X}", self.base_address.unwrap_or(0))?;
+        }
+        for offset in offsets {
+            write!(f, " -> {offset:#X}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Iterates over the ` -> `/`,`-separated segments of a pointer path, yielding
+/// each segment together with its byte position in the original input.
+struct SplitSegments<'a> {
+    rest: &'a str,
+    position: usize,
+}
+
+impl<'a> SplitSegments<'a> {
+    fn new(rest: &'a str, position: usize) -> Self {
+        Self { rest, position }
+    }
+}
+
+impl<'a> Iterator for SplitSegments<'a> {
+    type Item = (&'a str, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (end, skip) = match (self.rest.find("->"), self.rest.find(',')) {
+            (Some(arrow), Some(comma)) => {
+                if arrow < comma {
+                    (arrow, 2)
+                } else {
+                    (comma, 1)
+                }
+            }
+            (Some(arrow), None) => (arrow, 2),
+            (None, Some(comma)) => (comma, 1),
+            (None, None) => (self.rest.len(), 0),
+        };
+        let segment = &self.rest[..end];
+        let at = self.position;
+        self.position += end + skip;
+        self.rest = &self.rest[(end + skip).min(self.rest.len())..];
+        Some((segment, at))
+    }
+}
+
+/// Returns the size in bytes (1/2/4/8) of the smallest integer covering `span` bits,
+/// or [`None`] if `span` exceeds 64 bits.
+fn covering_bytes(span: u64) -> Option<u64> {
+    match span {
+        0..=8 => Some(1),
+        9..=16 => Some(2),
+        17..=32 => Some(4),
+        33..=64 => Some(8),
+        _ => None,
+    }
+}
+
+/// Returns a mask with the low `bit_width` bits set.
+fn bit_mask(bit_width: u64) -> u64 {
+    if bit_width >= 64 {
+        u64::MAX
+    } else {
+        (1 << bit_width) - 1
+    }
+}
+
+/// Shifts `raw` right by `shift` bits and masks off the low `bit_width` bits.
+fn extract_bits(raw: u64, shift: u64, bit_width: u64) -> u64 {
+    (raw >> shift) & bit_mask(bit_width)
+}
+
+/// Returns the number of bytes of leading whitespace of `text`.
+fn leading_ws(text: &str) -> usize {
+    text.len() - text.trim_start().len()
+}
+
+/// Parses a single offset token, accepting an optional sign, `0x`/`0X` hex or
+/// decimal digits, and surrounding whitespace. `at` is the byte position of the
+/// token in the original input, used for error reporting.
+fn parse_int(token: &str, at: usize) -> Result<u64, ParseError> {
+    let token = token.trim();
+    if token.is_empty() {
+        return Err(ParseError::new(at, ParseErrorKind::InvalidOffset));
+    }
+    let (negative, digits) = match token.strip_prefix('-') {
+        Some(rest) => (true, rest.trim_start()),
+        None => (false, token.strip_prefix('+').unwrap_or(token).trim_start()),
+    };
+    let value = if let Some(hex) = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        u64::from_str_radix(hex, 16)
+    } else {
+        digits.parse::<u64>()
+    }
+    .map_err(|_| ParseError::new(at, ParseErrorKind::InvalidOffset))?;
+    Ok(if negative {
+        (value as i64).wrapping_neg() as u64
+    } else {
+        value
+    })
+}
+
+/// An error produced while parsing a pointer path from its textual notation.
+#[derive(Copy, Clone, Debug)]
+pub struct ParseError {
+    /// The byte position in the input at which parsing failed.
+    pub position: usize,
+    /// The kind of malformed input that was encountered.
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    #[inline]
+    const fn new(position: usize, kind: ParseErrorKind) -> Self {
+        Self { position, kind }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self.kind {
+            ParseErrorKind::Empty => "the pointer path is empty",
+            ParseErrorKind::UnterminatedWidth => "the width marker is missing its closing `]`",
+            ParseErrorKind::InvalidWidth => "the width marker must be `[32]` or `[64]`",
+            ParseErrorKind::UnterminatedModule => "the module token is missing its closing `\"`",
+            ParseErrorKind::InvalidOffset => "the offset is not a valid hex or decimal number",
+            ParseErrorKind::TooManyOffsets => "the pointer path has more offsets than `CAP`",
+        };
+        write!(f, "{message} at byte {}", self.position)
+    }
+}
+
+/// The kind of malformed input encountered while parsing a pointer path.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input contained no base token.
+    Empty,
+    /// A `[` width marker was not closed with a `]`.
+    UnterminatedWidth,
+    /// The width marker was not `[32]` or `[64]`.
+    InvalidWidth,
+    /// A quoted module token was not closed with a `"`.
+    UnterminatedModule,
+    /// An offset token was not a valid hex or decimal number.
+    InvalidOffset,
+    /// The path had more offsets than the `CAP` of the target [`DeepPointer`].
+    TooManyOffsets,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate alloc;
+    use alloc::string::ToString;
+
+    #[test]
+    fn covering_bytes_boundaries() {
+        assert_eq!(covering_bytes(8), Some(1));
+        assert_eq!(covering_bytes(9), Some(2));
+        assert_eq!(covering_bytes(16), Some(2));
+        assert_eq!(covering_bytes(17), Some(4));
+        assert_eq!(covering_bytes(32), Some(4));
+        assert_eq!(covering_bytes(33), Some(8));
+        assert_eq!(covering_bytes(64), Some(8));
+        assert_eq!(covering_bytes(65), None);
+    }
+
+    #[test]
+    fn bit_mask_edges() {
+        assert_eq!(bit_mask(1), 0b1);
+        assert_eq!(bit_mask(8), 0xFF);
+        assert_eq!(bit_mask(63), (1 << 63) - 1);
+        assert_eq!(bit_mask(64), u64::MAX);
+    }
+
+    #[test]
+    fn extract_bits_straddles_byte_boundary() {
+        // Bits 6..=10 of 0x07C0 (0b0000_0111_1100_0000) read as a u16 at shift 6.
+        assert_eq!(extract_bits(0x07C0, 6, 5), 0b11111);
+        // A full 64-bit extraction returns the value unchanged.
+        assert_eq!(extract_bits(0xDEAD_BEEF_DEAD_BEEF, 0, 64), 0xDEAD_BEEF_DEAD_BEEF);
+        // A single bit past a byte boundary.
+        assert_eq!(extract_bits(0b1_0000_0000, 8, 1), 1);
+    }
+
+    #[test]
+    fn parse_module_round_trip() {
+        let path = ParsedPath::<8>::parse(r#"[64] "game.dll"+0x1234 -> 0x10 -> 0x48"#).unwrap();
+        assert_eq!(path.module, Some("game.dll"));
+        assert_eq!(path.base_address, None);
+        assert_eq!(&path.offsets[..], &[0x1234, 0x10, 0x48]);
+        assert_eq!(path.to_string(), r#"[64] "game.dll"+0x1234 -> 0x10 -> 0x48"#);
+    }
+
+    #[test]
+    fn parse_absolute_and_comma_form() {
+        let path = ParsedPath::<8>::parse("[32] 0x400000, 0x10, 0x0").unwrap();
+        assert!(matches!(path.deref_type, DerefType::Bit32));
+        assert_eq!(path.module, None);
+        assert_eq!(path.base_address, Some(0x400000));
+        assert_eq!(&path.offsets[..], &[0x10, 0x0]);
+        assert_eq!(path.to_string(), "[32] 0x400000 -> 0x10 -> 0x0");
+    }
+
+    #[test]
+    fn parse_signed_absolute_base() {
+        // A leading `+` is a signed absolute base, not an empty module.
+        let path = ParsedPath::<8>::parse("+0x1000 -> 0x10").unwrap();
+        assert_eq!(path.module, None);
+        assert_eq!(path.base_address, Some(0x1000));
+        assert_eq!(&path.offsets[..], &[0x10]);
+    }
+
+    #[test]
+    fn parse_accepts_signed_and_decimal_offsets() {
+        let path = ParsedPath::<8>::parse("4096 -> -0x4 -> +16").unwrap();
+        assert_eq!(path.base_address, Some(4096));
+        assert_eq!(path.offsets[0], (-4i64) as u64);
+        assert_eq!(path.offsets[1], 16);
+    }
+
+    #[test]
+    fn parse_malformed_reports_position() {
+        assert_eq!(
+            ParsedPath::<8>::parse("").unwrap_err().kind,
+            ParseErrorKind::Empty
+        );
+        assert_eq!(
+            ParsedPath::<8>::parse("[99] 0x10").unwrap_err().kind,
+            ParseErrorKind::InvalidWidth
+        );
+        assert_eq!(
+            ParsedPath::<8>::parse("[64 0x10").unwrap_err().kind,
+            ParseErrorKind::UnterminatedWidth
+        );
+        assert_eq!(
+            ParsedPath::<8>::parse(r#""mod+0x10"#).unwrap_err().kind,
+            ParseErrorKind::UnterminatedModule
+        );
+        let err = ParsedPath::<8>::parse("0x10 -> 0xZZ").unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::InvalidOffset);
+        assert_eq!(err.position, 8);
+    }
+
+    #[test]
+    fn parse_rejects_overflowing_cap() {
+        assert_eq!(
+            ParsedPath::<2>::parse("0x0 -> 0x1 -> 0x2 -> 0x3").unwrap_err().kind,
+            ParseErrorKind::TooManyOffsets
+        );
+    }
+}
@@ -0,0 +1,181 @@
+//! Support for tracking how a value changes between updates of the autosplitter logic.
+
+use core::ops::Deref;
+
+use bytemuck::CheckedBitPattern;
+
+use crate::{deep_pointer::DeepPointer, Address, Error, Process};
+
+/// A value together with the value it held on the previous update, as produced
+/// by [`Watcher::update`].
+#[derive(Copy, Clone, Default, Debug)]
+pub struct Pair<T> {
+    /// The value as it was on the previous update.
+    pub old: T,
+    /// The value as it is on the current update.
+    pub current: T,
+}
+
+impl<T> Deref for Pair<T> {
+    type Target = T;
+
+    /// A [`Pair`] dereferences to its `current` value, so it can be used in
+    /// place of the value it wraps.
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.current
+    }
+}
+
+impl<T: PartialEq> Pair<T> {
+    /// Returns [`true`] if the value changed between the previous and the
+    /// current update.
+    pub fn changed(&self) -> bool {
+        self.old != self.current
+    }
+
+    /// Returns [`true`] if the value changed to `value` on the current update.
+    pub fn changed_to(&self, value: &T) -> bool {
+        self.changed() && &self.current == value
+    }
+
+    /// Returns [`true`] if the value changed away from `value` on the current
+    /// update.
+    pub fn changed_from(&self, value: &T) -> bool {
+        self.changed() && &self.old == value
+    }
+
+    /// Returns [`true`] if the value changed from `old` to `current` on the
+    /// current update.
+    pub fn changed_from_to(&self, old: &T, current: &T) -> bool {
+        &self.old == old && &self.current == current && self.changed()
+    }
+}
+
+impl<T: PartialOrd> Pair<T> {
+    /// Returns [`true`] if the value increased between the previous and the
+    /// current update.
+    pub fn increased(&self) -> bool {
+        self.current > self.old
+    }
+
+    /// Returns [`true`] if the value decreased between the previous and the
+    /// current update.
+    pub fn decreased(&self) -> bool {
+        self.current < self.old
+    }
+}
+
+/// Tracks how a value read from a [`DeepPointer`] changes across the updates of
+/// the autosplitter logic. Call [`update`](Self::update) once per tick.
+#[derive(Clone, Debug)]
+pub struct Watcher<T, const CAP: usize> {
+    pointer: DeepPointer<CAP>,
+    pair: Option<Pair<T>>,
+}
+
+impl<T: CheckedBitPattern + PartialEq, const CAP: usize> Watcher<T, CAP> {
+    /// Creates a new `Watcher` reading from the given pointer path.
+    #[inline]
+    pub const fn new(pointer: DeepPointer<CAP>) -> Self {
+        Self {
+            pointer,
+            pair: None,
+        }
+    }
+
+    /// Returns the most recently observed [`Pair`], or [`None`] if the watcher
+    /// has not been updated yet.
+    #[inline]
+    pub fn pair(&self) -> Option<Pair<T>> {
+        self.pair
+    }
+
+    /// Seeds both `old` and `current` with `value`, so the next update reports a
+    /// change only if the value it reads differs from `value`
+    pub fn seed(&mut self, value: T) {
+        self.pair = Some(Pair {
+            old: value,
+            current: value,
+        });
+    }
+
+    /// Reads the current value, shifts the previous one into `old`, and returns the [`Pair`].
+    /// On the first update `old` is seeded with the read value so it does not report a change.
+    pub fn update(&mut self, process: &Process) -> Result<Pair<T>, Error> {
+        let current = self.pointer.deref(process)?;
+        Ok(self.update_value(current))
+    }
+
+    /// Like [`update`](Self::update), but follows the pointer path starting from
+    /// the provided `base_address`.
+    pub fn update_from(
+        &mut self,
+        process: &Process,
+        base_address: impl Into<Address>,
+    ) -> Result<Pair<T>, Error> {
+        let current = self.pointer.deref_from(process, base_address)?;
+        Ok(self.update_value(current))
+    }
+
+    /// Updates the watcher with a value obtained elsewhere, shifting the previous one into `old`
+    pub fn update_value(&mut self, current: T) -> Pair<T> {
+        let old = self.pair.map_or(current, |pair| pair.current);
+        let pair = Pair { old, current };
+        self.pair = Some(pair);
+        pair
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn predicates() {
+        let pair = Pair { old: 1, current: 2 };
+        assert!(pair.changed());
+        assert!(pair.changed_to(&2));
+        assert!(!pair.changed_to(&3));
+        assert!(pair.changed_from(&1));
+        assert!(!pair.changed_from(&0));
+        assert!(pair.changed_from_to(&1, &2));
+        assert!(!pair.changed_from_to(&0, &2));
+        assert!(pair.increased());
+        assert!(!pair.decreased());
+
+        let same = Pair { old: 2, current: 2 };
+        assert!(!same.changed());
+        assert!(!same.changed_to(&2));
+        assert!(!same.increased());
+        assert!(!same.decreased());
+    }
+
+    #[test]
+    fn first_update_reports_no_change() {
+        let mut watcher = Watcher::<u32, 1>::new(DeepPointer::default());
+        let pair = watcher.update_value(5);
+        assert_eq!(pair.old, 5);
+        assert_eq!(pair.current, 5);
+        assert!(!pair.changed());
+    }
+
+    #[test]
+    fn subsequent_updates_shift_generations() {
+        let mut watcher = Watcher::<u32, 1>::new(DeepPointer::default());
+        watcher.update_value(1);
+        let pair = watcher.update_value(2);
+        assert_eq!(pair.old, 1);
+        assert_eq!(pair.current, 2);
+        assert!(pair.changed());
+    }
+
+    #[test]
+    fn seed_suppresses_first_change() {
+        let mut watcher = Watcher::<u32, 1>::new(DeepPointer::default());
+        watcher.seed(7);
+        assert_eq!(watcher.pair().unwrap().current, 7);
+        let pair = watcher.update_value(7);
+        assert!(!pair.changed());
+    }
+}